@@ -382,7 +382,18 @@ where
                         let json = json
                             .as_string()
                             .expect("couldn't get String from JsString");
-                        if (500..=599).contains(&status) {
+                        let retry_after =
+                            resp.headers().get("Retry-After").ok().flatten();
+                        if let Some(err) = server_fn::error_for_status(
+                            status,
+                            &json,
+                            retry_after.as_deref(),
+                        ) {
+                            value.try_set(Some(Err(err)));
+                            if let Some(error) = error {
+                                error.try_set(None);
+                            }
+                        } else if (500..=599).contains(&status) {
                             match serde_json::from_str::<ServerFnError>(&json) {
                                 Ok(res) => {
                                     value.try_set(Some(Err(res)));
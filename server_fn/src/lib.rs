@@ -460,6 +460,25 @@ pub enum ServerFnError {
     /// Occurs on the client if there is a network error while trying to run function on server.
     #[error("error reaching server to call server function: {0}")]
     Request(String),
+    /// Occurs on native (non-WASM) clients if the request takes longer than the timeout set
+    /// with [`set_server_timeout`].
+    #[error("timed out while calling server function: {0}")]
+    Timeout(String),
+    /// Occurs on the client if the server responds with `429 Too Many Requests`.
+    #[error("too many requests to server function")]
+    RateLimited {
+        /// The number of seconds the server asked the client to wait before retrying,
+        /// if it sent a `Retry-After` header given in seconds.
+        retry_after_secs: Option<u64>,
+    },
+    /// Occurs on the client if the server responds with `401 Unauthorized`, for example from
+    /// authentication middleware. Holds the body of the response.
+    #[error("not authorized to call server function: {0}")]
+    Unauthorized(String),
+    /// Occurs on the client if the server responds with `403 Forbidden`. Holds the body of the
+    /// response.
+    #[error("forbidden from calling server function: {0}")]
+    Forbidden(String),
     /// Occurs when there is an error while actually running the function on the server.
     #[error("error running server function: {0}")]
     ServerError(String),
@@ -477,6 +496,32 @@ pub enum ServerFnError {
     MissingArg(String),
 }
 
+/// Parses the value of a `Retry-After` header given in seconds, as used to fill in
+/// [`ServerFnError::RateLimited`]. Returns `None` for the HTTP-date form or any other value
+/// that isn't a non-negative number of seconds.
+pub fn parse_retry_after(value: &str) -> Option<u64> {
+    value.trim().parse().ok()
+}
+
+/// Maps a response status that the client treats as an error before decoding the body into the
+/// matching [`ServerFnError`]: `401` into [`ServerFnError::Unauthorized`], `403` into
+/// [`ServerFnError::Forbidden`], and `429` into [`ServerFnError::RateLimited`]. Returns `None`
+/// for any other status.
+pub fn error_for_status(
+    status: u16,
+    body: &str,
+    retry_after: Option<&str>,
+) -> Option<ServerFnError> {
+    match status {
+        401 => Some(ServerFnError::Unauthorized(body.to_string())),
+        403 => Some(ServerFnError::Forbidden(body.to_string())),
+        429 => Some(ServerFnError::RateLimited {
+            retry_after_secs: retry_after.and_then(parse_retry_after),
+        }),
+        _ => None,
+    }
+}
+
 /// Executes the HTTP call to call a server function from the client, given its URL and argument type.
 #[cfg(not(feature = "ssr"))]
 pub async fn call_server_fn<T, C: 'static>(
@@ -565,22 +610,22 @@ where
     #[cfg(not(target_arch = "wasm32"))]
     let resp = match &enc {
         Encoding::Url | Encoding::Cbor => match args_encoded {
-            Payload::Binary(b) => CLIENT
+            Payload::Binary(b) => client()
                 .post(url)
                 .header("Content-Type", content_type_header)
                 .header("Accept", accept_header)
                 .body(b)
                 .send()
                 .await
-                .map_err(|e| ServerFnError::Request(e.to_string()))?,
-            Payload::Url(s) => CLIENT
+                .map_err(|e| map_reqwest_error(e, ServerFnError::Request))?,
+            Payload::Url(s) => client()
                 .post(url)
                 .header("Content-Type", content_type_header)
                 .header("Accept", accept_header)
                 .body(s)
                 .send()
                 .await
-                .map_err(|e| ServerFnError::Request(e.to_string()))?,
+                .map_err(|e| map_reqwest_error(e, ServerFnError::Request))?,
         },
         Encoding::GetJSON | Encoding::GetCBOR => match args_encoded {
             Payload::Binary(_) => panic!(
@@ -590,13 +635,13 @@ where
 
             Payload::Url(s) => {
                 let full_url = format!("{url}?{s}");
-                CLIENT
+                client()
                     .get(full_url)
                     .header("Content-Type", content_type_header)
                     .header("Accept", accept_header)
                     .send()
                     .await
-                    .map_err(|e| ServerFnError::Request(e.to_string()))?
+                    .map_err(|e| map_reqwest_error(e, ServerFnError::Request))?
             }
         },
     };
@@ -605,6 +650,23 @@ where
     let status = resp.status();
     #[cfg(not(target_arch = "wasm32"))]
     let status = status.as_u16();
+    // only read the body here for the statuses `error_for_status` maps, because
+    // any other response body still needs to be decoded below
+    if matches!(status, 401 | 403 | 429) {
+        #[cfg(target_arch = "wasm32")]
+        let retry_after = resp.headers().get("Retry-After");
+        #[cfg(not(target_arch = "wasm32"))]
+        let retry_after = resp
+            .headers()
+            .get("Retry-After")
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+        let text = resp.text().await.unwrap_or_default();
+        if let Some(e) = error_for_status(status, &text, retry_after.as_deref())
+        {
+            return Err(e);
+        }
+    }
     if (500..=599).contains(&status) {
         let text = resp.text().await.unwrap_or_default();
         #[cfg(target_arch = "wasm32")]
@@ -625,20 +687,24 @@ where
         #[cfg(target_arch = "wasm32")]
         let binary = binary.as_slice();
         #[cfg(not(target_arch = "wasm32"))]
-        let binary = resp
-            .bytes()
-            .await
-            .map_err(|e| ServerFnError::Deserialization(e.to_string()))?;
+        let binary = resp.bytes().await.map_err(|e| {
+            map_reqwest_error(e, ServerFnError::Deserialization)
+        })?;
         #[cfg(not(target_arch = "wasm32"))]
         let binary = binary.as_ref();
 
         ciborium::de::from_reader(binary)
             .map_err(|e| ServerFnError::Deserialization(e.to_string()))
     } else {
+        #[cfg(target_arch = "wasm32")]
         let text = resp
             .text()
             .await
             .map_err(|e| ServerFnError::Deserialization(e.to_string()))?;
+        #[cfg(not(target_arch = "wasm32"))]
+        let text = resp.text().await.map_err(|e| {
+            map_reqwest_error(e, ServerFnError::Deserialization)
+        })?;
 
         let mut deserializer = JSONDeserializer::from_str(&text);
         T::deserialize(&mut deserializer)
@@ -646,10 +712,38 @@ where
     }
 }
 
-// Lazily initialize the client to be reused for all server function calls.
+// Maps an error from `reqwest` into a server function error. Timeouts are
+// reported as such; any other error becomes `otherwise`, which is `Request`
+// when sending the request and `Deserialization` when reading the response.
+#[cfg(all(not(feature = "ssr"), not(target_arch = "wasm32")))]
+fn map_reqwest_error(
+    e: reqwest::Error,
+    otherwise: fn(String) -> ServerFnError,
+) -> ServerFnError {
+    if e.is_timeout() {
+        ServerFnError::Timeout(e.to_string())
+    } else {
+        otherwise(e.to_string())
+    }
+}
+
+// The client reused for all server function calls, built on first use.
 #[cfg(any(all(not(feature = "ssr"), not(target_arch = "wasm32")), doc))]
-static CLIENT: once_cell::sync::Lazy<reqwest::Client> =
-    once_cell::sync::Lazy::new(|| reqwest::Client::new());
+static CLIENT: once_cell::sync::OnceCell<reqwest::Client> =
+    once_cell::sync::OnceCell::new();
+
+#[cfg(all(not(feature = "ssr"), not(target_arch = "wasm32")))]
+fn client() -> &'static reqwest::Client {
+    CLIENT.get_or_init(|| {
+        let mut builder = reqwest::Client::builder();
+        if let Some(timeout) = TIMEOUT.get() {
+            builder = builder.timeout(*timeout);
+        }
+        builder
+            .build()
+            .expect("couldn't build the server function client")
+    })
+}
 
 #[cfg(any(all(not(feature = "ssr"), not(target_arch = "wasm32")), doc))]
 static ROOT_URL: once_cell::sync::OnceCell<&'static str> =
@@ -661,9 +755,118 @@ pub fn set_server_url(url: &'static str) {
     ROOT_URL.set(url).unwrap();
 }
 
+#[cfg(any(all(not(feature = "ssr"), not(target_arch = "wasm32")), doc))]
+static TIMEOUT: once_cell::sync::OnceCell<std::time::Duration> =
+    once_cell::sync::OnceCell::new();
+
+#[cfg(any(all(not(feature = "ssr"), not(target_arch = "wasm32")), doc))]
+/// Set a timeout for every server function call made by the client. If a call takes longer than
+/// this, it fails with [`ServerFnError::Timeout`]. This only applies to non-WASM clients.
+///
+/// # Panics
+/// Panics if it is called more than once, or after the first server function call.
+pub fn set_server_timeout(timeout: std::time::Duration) {
+    if CLIENT.get().is_some() {
+        panic!(
+            "Call set_server_timeout before calling a server function; the \
+             timeout cannot be changed once the client has been built."
+        );
+    }
+    TIMEOUT.set(timeout).unwrap();
+}
+
 #[cfg(all(not(feature = "ssr"), not(target_arch = "wasm32")))]
 fn get_server_url() -> &'static str {
     ROOT_URL
         .get()
         .expect("Call set_root_url before calling a server function.")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{error_for_status, parse_retry_after, ServerFnError};
+
+    #[test]
+    fn parse_retry_after_seconds() {
+        assert_eq!(parse_retry_after("120"), Some(120));
+        assert_eq!(parse_retry_after(" 5 "), Some(5));
+    }
+
+    #[test]
+    fn parse_retry_after_rejects_other_values() {
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+        assert_eq!(parse_retry_after("-1"), None);
+    }
+
+    #[test]
+    fn error_for_status_maps_auth_statuses() {
+        assert!(matches!(
+            error_for_status(401, "log in first", None),
+            Some(ServerFnError::Unauthorized(body)) if body == "log in first"
+        ));
+        assert!(matches!(
+            error_for_status(403, "admins only", None),
+            Some(ServerFnError::Forbidden(body)) if body == "admins only"
+        ));
+    }
+
+    #[test]
+    fn error_for_status_maps_rate_limiting() {
+        assert!(matches!(
+            error_for_status(429, "", Some("30")),
+            Some(ServerFnError::RateLimited {
+                retry_after_secs: Some(30)
+            })
+        ));
+        assert!(matches!(
+            error_for_status(429, "", None),
+            Some(ServerFnError::RateLimited {
+                retry_after_secs: None
+            })
+        ));
+    }
+
+    #[test]
+    fn error_for_status_ignores_other_statuses() {
+        assert!(error_for_status(200, "null", None).is_none());
+        assert!(error_for_status(500, "{}", None).is_none());
+        assert!(error_for_status(503, "", Some("30")).is_none());
+    }
+
+    #[test]
+    fn client_error_variants_round_trip_through_json() {
+        fn round_trip(err: ServerFnError) -> ServerFnError {
+            let json = serde_json::to_string(&err).unwrap();
+            serde_json::from_str(&json).unwrap()
+        }
+
+        assert!(matches!(
+            round_trip(ServerFnError::RateLimited {
+                retry_after_secs: None
+            }),
+            ServerFnError::RateLimited {
+                retry_after_secs: None
+            }
+        ));
+        assert!(matches!(
+            round_trip(ServerFnError::RateLimited {
+                retry_after_secs: Some(30)
+            }),
+            ServerFnError::RateLimited {
+                retry_after_secs: Some(30)
+            }
+        ));
+        assert!(matches!(
+            round_trip(ServerFnError::Timeout("slow".into())),
+            ServerFnError::Timeout(msg) if msg == "slow"
+        ));
+        assert!(matches!(
+            round_trip(ServerFnError::Unauthorized("log in".into())),
+            ServerFnError::Unauthorized(msg) if msg == "log in"
+        ));
+        assert!(matches!(
+            round_trip(ServerFnError::Forbidden("admins only".into())),
+            ServerFnError::Forbidden(msg) if msg == "admins only"
+        ));
+    }
+}